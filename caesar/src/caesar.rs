@@ -7,10 +7,18 @@ pub enum Mode {
     Decrypt,
 }
 
+const ALPHABET: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e',
+    'f', 'g', 'h', 'i', 'j',
+    'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't',
+    'u', 'v', 'w', 'x', 'y', 'z'
+];
+
 #[derive(Debug, PartialEq)]
 pub struct KeyError;
 
-const KEY_ERROR_MSG: &'static str = "the key parameter must be a positive number between 0 - 999999.";
+const KEY_ERROR_MSG: &str = "the key parameter must be a positive number between 0 - 999999.";
 
 impl Display for KeyError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -24,6 +32,10 @@ impl Error for KeyError {
     }
 }
 
+/// Shifts every ASCII letter in `input` by a constant `key`, wrapping
+/// within a-z/A-Z and passing every other character (including non-ASCII
+/// letters) through unchanged. For a reusable cipher, or for input types other than `&str`,
+/// see [`Caesar`].
 pub fn caesar(input: &str, key: i32, dir: Mode) -> Result<String, KeyError> {
     if key.is_negative() {
         return Err(KeyError);
@@ -32,31 +44,161 @@ pub fn caesar(input: &str, key: i32, dir: Mode) -> Result<String, KeyError> {
         return Err(KeyError);
     }
 
-    let alphabet_pos = [
-        'a', 'b', 'c', 'd', 'e',
-        'f', 'g', 'h', 'i', 'j',
-        'k', 'l', 'm', 'n', 'o',
-        'p', 'q', 'r', 's', 't',
-        'u', 'v', 'w', 'x', 'y', 'z'
-    ];
+    let alphabet_dic = build_alphabet_dic();
+    Ok(shift_str(input, key, &dir, &alphabet_dic))
+}
+
+/// A reusable Caesar cipher with a fixed, normalized shift.
+///
+/// Unlike the free [`caesar`] function, a negative shift or one above
+/// 999 999 is not an error: every shift is wrapped with `rem_euclid(26)` when the cipher is
+/// constructed. `encrypt`/`decrypt` work over `&str`, `encrypt_bytes`/
+/// `decrypt_bytes` over `&[u8]`, and `encrypt_chars`/`decrypt_chars` over
+/// anything implementing `IntoIterator<Item = char>`. `encrypt_mut`
+/// transforms a byte buffer in place without allocating.
+pub struct Caesar {
+    shift: i32,
+}
+
+impl Caesar {
+    pub fn new(shift: i32) -> Self {
+        Caesar { shift: shift.rem_euclid(26) }
+    }
 
+    /// A `Caesar` cipher with the classic ROT13 shift.
+    pub fn rot13() -> Self {
+        Caesar::new(13)
+    }
+
+    pub fn encrypt(&self, input: &str) -> String {
+        let alphabet_dic = build_alphabet_dic();
+        shift_str(input, self.shift, &Mode::Encrypt, &alphabet_dic)
+    }
+
+    pub fn decrypt(&self, input: &str) -> String {
+        let alphabet_dic = build_alphabet_dic();
+        shift_str(input, self.shift, &Mode::Decrypt, &alphabet_dic)
+    }
+
+    pub fn encrypt_bytes(&self, input: &[u8]) -> Vec<u8> {
+        input.iter().map(|b| shift_byte(*b, self.shift, &Mode::Encrypt)).collect()
+    }
+
+    pub fn decrypt_bytes(&self, input: &[u8]) -> Vec<u8> {
+        input.iter().map(|b| shift_byte(*b, self.shift, &Mode::Decrypt)).collect()
+    }
+
+    pub fn encrypt_chars<I: IntoIterator<Item = char>>(&self, input: I) -> String {
+        let alphabet_dic = build_alphabet_dic();
+        input.into_iter().map(|c| shift_char(c, self.shift, &Mode::Encrypt, &alphabet_dic)).collect()
+    }
+
+    pub fn decrypt_chars<I: IntoIterator<Item = char>>(&self, input: I) -> String {
+        let alphabet_dic = build_alphabet_dic();
+        input.into_iter().map(|c| shift_char(c, self.shift, &Mode::Decrypt, &alphabet_dic)).collect()
+    }
+
+    /// Transforms `buffer` in place, leaving non-ASCII-letter bytes untouched.
+    pub fn encrypt_mut(&self, buffer: &mut [u8]) {
+        for b in buffer.iter_mut() {
+            *b = shift_byte(*b, self.shift, &Mode::Encrypt);
+        }
+    }
+}
+
+pub(crate) fn build_alphabet_dic() -> HashMap<char, usize> {
     let mut alphabet_dic = HashMap::new();
-    for (index, letter) in alphabet_pos.iter().enumerate() {
+    for (index, letter) in ALPHABET.iter().enumerate() {
         alphabet_dic.insert(*letter, index);
     }
+    alphabet_dic
+}
+
+pub(crate) fn shift_str(input: &str, shift: i32, dir: &Mode, alphabet_dic: &HashMap<char, usize>) -> String {
+    input.chars().map(|c| shift_char(c, shift, dir, alphabet_dic)).collect()
+}
+
+pub(crate) fn shift_char(c: char, shift: i32, dir: &Mode, alphabet_dic: &HashMap<char, usize>) -> char {
+    let lower: Vec<_> = c.to_lowercase().collect();
+    match alphabet_dic.get(lower.first().unwrap()) {
+        Some(index) => {
+            let calc_index = match dir {
+                Mode::Encrypt => calc_index_forward(index, shift),
+                Mode::Decrypt => calc_index_backward(index, shift)
+            };
+            let matched = ALPHABET[calc_index];
+            if c.is_uppercase() {
+                matched.to_uppercase().next().unwrap()
+            } else {
+                matched
+            }
+        }
+        None => c
+    }
+}
+
+/// Shifts a single ASCII letter byte, leaving any other byte untouched.
+pub(crate) fn shift_byte(b: u8, shift: i32, dir: &Mode) -> u8 {
+    let base = if b.is_ascii_lowercase() {
+        b'a'
+    } else if b.is_ascii_uppercase() {
+        b'A'
+    } else {
+        return b;
+    };
+    let index = (b - base) as usize;
+    let calc_index = match dir {
+        Mode::Encrypt => calc_index_forward(&index, shift),
+        Mode::Decrypt => calc_index_backward(&index, shift)
+    };
+    base + calc_index as u8
+}
+
+/// Like [`caesar`], but the shift applied to each letter is not constant: it
+/// is derived from the letter's position among the ASCII letters seen so
+/// far. This is the common "progressive Caesar" CTF variant, where every
+/// letter is rotated by a different amount.
+///
+/// All other characters are passed through unchanged and, matching
+/// `caesar`, do not advance the position counter.
+pub fn caesar_progressive(input: &str, key: i32, dir: Mode) -> Result<String, KeyError> {
+    caesar_progressive_with(input, key, dir, |pos| (pos % 26) as i32)
+}
+
+/// Same as [`caesar_progressive`], but lets the caller supply a custom
+/// per-position offset schedule instead of the default `pos`-th shift.
+pub fn caesar_progressive_with<F>(
+    input: &str,
+    key: i32,
+    dir: Mode,
+    schedule: F,
+) -> Result<String, KeyError>
+where
+    F: Fn(usize) -> i32,
+{
+    if key.is_negative() {
+        return Err(KeyError);
+    }
+    if key > 999_999 {
+        return Err(KeyError);
+    }
+
+    let alphabet_dic = build_alphabet_dic();
 
     let mut result = String::new();
+    let mut pos: usize = 0;
 
     for ic in input.chars() {
         let ic_lower: Vec<_> = ic.to_lowercase().collect();
-        match alphabet_dic.get(&ic_lower.get(0).unwrap()) {
+        match alphabet_dic.get(ic_lower.first().unwrap()) {
             Some(index) => {
-                let calc_index: usize;
-                match dir {
-                    Mode::Encrypt => calc_index = calc_index_forward(index, key),
-                    Mode::Decrypt => calc_index = calc_index_backward(index, key)
-                }
-                let matched_char = alphabet_pos.get(calc_index).unwrap().to_string();
+                let step = key + schedule(pos);
+                pos += 1;
+                let calc_index: usize = match dir {
+                    Mode::Encrypt => calc_index_forward(index, step),
+                    Mode::Decrypt => calc_index_backward(index, step)
+                };
+                let matched_char = ALPHABET.get(calc_index).unwrap().to_string();
                 if ic.is_uppercase() {
                     result.push_str(matched_char.to_uppercase().as_str());
                     continue;
@@ -69,25 +211,25 @@ pub fn caesar(input: &str, key: i32, dir: Mode) -> Result<String, KeyError> {
     Ok(result)
 }
 
-fn calc_index_forward(letter_index: &usize, key: i32) -> usize {
+pub(crate) fn calc_index_forward(letter_index: &usize, key: i32) -> usize {
     let li = *letter_index as i32;
     let result = (li + key) % 26;
-    return result as usize;
+    result as usize
 }
 
-fn calc_index_backward(letter_index: &usize, key: i32) -> usize {
+pub(crate) fn calc_index_backward(letter_index: &usize, key: i32) -> usize {
     let li = *letter_index as i32;
     let mut result = (li - key) % 26;
     if result.is_negative() {
-        result = 26 + result
+        result += 26
     }
-    return result as usize;
+    result as usize
 }
 
 
 #[cfg(test)]
 mod tests {
-    use crate::caesar::{caesar, KeyError, Mode};
+    use crate::caesar::{caesar, caesar_progressive, caesar_progressive_with, Caesar, KeyError, Mode};
 
     #[test]
     fn it_encrypts_basic_string() {
@@ -203,4 +345,79 @@ mod tests {
         let result = caesar("ABC", 999_999, Mode::Encrypt).unwrap();
         assert_eq!("NOP", result);
     }
+
+    #[test]
+    fn it_encrypts_with_an_increasing_shift_per_letter() {
+        let result = caesar_progressive("AAAA", 1, Mode::Encrypt).unwrap();
+        assert_eq!("BCDE", result);
+    }
+
+    #[test]
+    fn it_decrypts_with_an_increasing_shift_per_letter() {
+        let result = caesar_progressive("BCDE", 1, Mode::Decrypt).unwrap();
+        assert_eq!("AAAA", result);
+    }
+
+    #[test]
+    fn it_does_not_advance_position_on_non_alphabet_characters() {
+        let result = caesar_progressive("A.A", 1, Mode::Encrypt).unwrap();
+        assert_eq!("B.C", result);
+    }
+
+    #[test]
+    fn it_accepts_a_custom_offset_schedule() {
+        let result = caesar_progressive_with("AAA", 0, Mode::Encrypt, |pos| pos as i32 * 2).unwrap();
+        assert_eq!("ACE", result);
+    }
+
+    #[test]
+    fn it_returns_error_on_negative_key_for_progressive() {
+        let result = caesar_progressive("ABC", -1, Mode::Encrypt).unwrap_err();
+        assert_eq!(KeyError, result);
+    }
+
+    #[test]
+    fn it_encrypts_and_decrypts_a_str_via_the_caesar_type() {
+        let cipher = Caesar::new(1);
+        assert_eq!("BCD", cipher.encrypt("ABC"));
+        assert_eq!("ABC", cipher.decrypt("BCD"));
+    }
+
+    #[test]
+    fn it_normalizes_out_of_range_shifts_instead_of_erroring() {
+        let cipher = Caesar::new(27);
+        assert_eq!("BCD", cipher.encrypt("ABC"));
+
+        let cipher = Caesar::new(-1);
+        assert_eq!("ZAB", cipher.encrypt("ABC"));
+    }
+
+    #[test]
+    fn it_builds_rot13() {
+        let cipher = Caesar::rot13();
+        assert_eq!("NOP", cipher.encrypt("ABC"));
+        assert_eq!("ABC", cipher.decrypt("NOP"));
+    }
+
+    #[test]
+    fn it_encrypts_and_decrypts_byte_slices() {
+        let cipher = Caesar::new(1);
+        assert_eq!(b"BCD".to_vec(), cipher.encrypt_bytes(b"ABC"));
+        assert_eq!(b"ABC".to_vec(), cipher.decrypt_bytes(b"BCD"));
+    }
+
+    #[test]
+    fn it_encrypts_a_char_iterator() {
+        let cipher = Caesar::new(1);
+        let result = cipher.encrypt_chars("ABC".chars());
+        assert_eq!("BCD", result);
+    }
+
+    #[test]
+    fn it_encrypts_a_buffer_in_place() {
+        let cipher = Caesar::new(1);
+        let mut buffer = b"ABC".to_vec();
+        cipher.encrypt_mut(&mut buffer);
+        assert_eq!(b"BCD".to_vec(), buffer);
+    }
 }
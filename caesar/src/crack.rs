@@ -0,0 +1,88 @@
+use crate::caesar::{build_alphabet_dic, shift_str, Mode};
+
+/// Standard relative frequency of each letter a-z in English text, used by
+/// [`crack`] to score candidate decryptions.
+const ENGLISH_LETTER_FREQ: [f64; 26] = [
+    0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094,
+    0.06966, 0.00153, 0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929,
+    0.00095, 0.05987, 0.06327, 0.09056, 0.02758, 0.00978, 0.02360, 0.00150,
+    0.01974, 0.00074,
+];
+
+/// Brute-forces a Caesar-encrypted `ciphertext` of unknown key by trying
+/// every shift `0..26` and scoring the resulting letter-frequency
+/// distribution against [`ENGLISH_LETTER_FREQ`] with a chi-squared distance.
+///
+/// Returns all 26 candidates as `(key, decrypted, score)`, sorted ascending
+/// by score, so `result[0]` is the most English-like guess.
+pub fn crack(ciphertext: &str) -> Vec<(i32, String, f64)> {
+    let alphabet_dic = build_alphabet_dic();
+
+    let mut candidates: Vec<(i32, String, f64)> = (0..26)
+        .map(|key| {
+            let decrypted = shift_str(ciphertext, key, &Mode::Decrypt, &alphabet_dic);
+            let score = chi_squared_score(&decrypted);
+            (key, decrypted, score)
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+    candidates
+}
+
+fn chi_squared_score(text: &str) -> f64 {
+    let mut counts = [0f64; 26];
+    let mut total = 0f64;
+
+    for c in text.chars() {
+        if c.is_ascii_alphabetic() {
+            let index = (c.to_ascii_lowercase() as u8 - b'a') as usize;
+            counts[index] += 1.0;
+            total += 1.0;
+        }
+    }
+
+    if total == 0.0 {
+        return f64::MAX;
+    }
+
+    ENGLISH_LETTER_FREQ
+        .iter()
+        .enumerate()
+        .map(|(index, freq)| {
+            let expected = total * freq;
+            let observed = counts[index];
+            (observed - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::caesar::{caesar, Mode};
+    use crate::crack::crack;
+
+    #[test]
+    fn it_cracks_a_caesar_ciphertext_with_unknown_key() {
+        let plaintext = "the quick brown fox jumps over the lazy dog \
+            the five boxing wizards jump quickly pack my box with five dozen liquor jugs";
+        let ciphertext = caesar(plaintext, 7, Mode::Encrypt).unwrap();
+
+        let candidates = crack(&ciphertext);
+
+        assert_eq!(26, candidates.len());
+        let (best_key, best_guess, _) = &candidates[0];
+        assert_eq!(7, *best_key);
+        assert_eq!(&plaintext, best_guess);
+    }
+
+    #[test]
+    fn it_ranks_candidates_ascending_by_score() {
+        let ciphertext = caesar("the quick brown fox jumps over the lazy dog", 3, Mode::Encrypt).unwrap();
+        let candidates = crack(&ciphertext);
+        for pair in candidates.windows(2) {
+            assert!(pair[0].2 <= pair[1].2);
+        }
+    }
+}
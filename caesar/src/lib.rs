@@ -0,0 +1,4 @@
+pub mod caesar;
+pub mod crack;
+pub mod stream;
+pub mod vigenere;
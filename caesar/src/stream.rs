@@ -0,0 +1,71 @@
+//! [`cipher::StreamCipher`] integration for the Caesar shift, so it can be
+//! dropped into pipelines built around the RustCrypto `cipher` traits
+//! alongside ciphers such as `chacha20`.
+
+use cipher::{
+    consts::{U0, U1}, inout::InOutBuf, Iv, Key, IvSizeUser, KeyIvInit, KeySizeUser, StreamCipher,
+    StreamCipherError,
+};
+
+use crate::caesar::{shift_byte, Mode};
+
+/// A Caesar shift exposed through the `cipher` crate's `StreamCipher`
+/// trait, so code written generically over that trait (e.g. a test
+/// harness that drives arbitrary `&mut dyn StreamCipher` values) can apply
+/// it the same way it would apply a real stream cipher's keystream.
+///
+/// The "keystream" is trivial: a constant per-byte shift applied only to
+/// ASCII letter bytes, leaving every other byte untouched. `CaesarStream`
+/// takes no nonce (`IvSize = U0`) but implements `KeyIvInit` rather than
+/// the IV-less `KeyInit`, matching the shape expected by code that's
+/// generic over ciphers like `chacha20` which do take one.
+///
+/// Unlike a real stream cipher's XOR keystream, this one is not its own
+/// inverse: `apply_keystream` always shifts forward, so `CaesarStream`
+/// only fits harnesses that apply a cipher in one direction. To decrypt,
+/// construct a separate `CaesarStream` with the complementary key byte
+/// `(26 - shift) % 26`, the same way `Caesar::decrypt` uses the mirrored
+/// `calc_index_backward` rather than re-running `calc_index_forward`.
+pub struct CaesarStream {
+    shift: i32,
+}
+
+impl KeySizeUser for CaesarStream {
+    type KeySize = U1;
+}
+
+impl IvSizeUser for CaesarStream {
+    type IvSize = U0;
+}
+
+impl KeyIvInit for CaesarStream {
+    fn new(key: &Key<Self>, _iv: &Iv<Self>) -> Self {
+        CaesarStream { shift: (key[0] as i32).rem_euclid(26) }
+    }
+}
+
+impl StreamCipher for CaesarStream {
+    fn try_apply_keystream_inout(&mut self, mut buf: InOutBuf<'_, '_, u8>) -> Result<(), StreamCipherError> {
+        let input = buf.get_in().to_vec();
+        for (o, i) in buf.get_out().iter_mut().zip(input.iter()) {
+            *o = shift_byte(*i, self.shift, &Mode::Encrypt);
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use cipher::{KeyIvInit, StreamCipher};
+
+    use super::CaesarStream;
+
+    #[test]
+    fn it_shifts_ascii_letters_and_leaves_other_bytes_untouched() {
+        let mut cipher = CaesarStream::new(&[1].into(), &Default::default());
+        let mut buffer = *b"ABC.";
+        cipher.apply_keystream(&mut buffer);
+        assert_eq!(b"BCD.", &buffer);
+    }
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::caesar::{build_alphabet_dic, shift_char, Mode};
+
+#[derive(Debug, PartialEq)]
+pub struct KeywordError;
+
+const KEYWORD_ERROR_MSG: &str = "the keyword must be a non-empty string of ASCII alphabetic characters.";
+
+impl Display for KeywordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", KEYWORD_ERROR_MSG)
+    }
+}
+
+impl Error for KeywordError {
+    fn description(&self) -> &str {
+        KEYWORD_ERROR_MSG
+    }
+}
+
+/// Encrypts or decrypts `input` with a keyword-driven Vigenère cipher: the
+/// n-th ASCII letter of `input` is shifted by the alphabet position of the
+/// n-th letter of `keyword` (cycling the keyword), reusing the same
+/// forward/backward shift arithmetic as [`crate::caesar::caesar`].
+///
+/// As in `caesar`, all other characters are passed through unchanged and
+/// capitalization is preserved. `keyword` must be non-empty and made of
+/// ASCII letters only, or a [`KeywordError`] is returned.
+pub fn vigenere(input: &str, keyword: &str, dir: Mode) -> Result<String, KeywordError> {
+    if keyword.is_empty() || !keyword.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(KeywordError);
+    }
+
+    let alphabet_dic = build_alphabet_dic();
+
+    let keyword_shifts: Vec<i32> = keyword
+        .to_lowercase()
+        .chars()
+        .map(|c| *alphabet_dic.get(&c).unwrap() as i32)
+        .collect();
+
+    let mut result = String::new();
+    let mut pos: usize = 0;
+
+    for ic in input.chars() {
+        let ic_lower: Vec<_> = ic.to_lowercase().collect();
+        if !alphabet_dic.contains_key(ic_lower.first().unwrap()) {
+            result.push(ic);
+            continue;
+        }
+        let key = keyword_shifts[pos % keyword_shifts.len()];
+        pos += 1;
+        result.push(shift_char(ic, key, &dir, &alphabet_dic));
+    }
+    Ok(result)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::caesar::Mode;
+    use crate::vigenere::{vigenere, KeywordError};
+
+    #[test]
+    fn it_encrypts_basic_string() {
+        let result = vigenere("ATTACKATDAWN", "LEMON", Mode::Encrypt).unwrap();
+        assert_eq!("LXFOPVEFRNHR", result);
+    }
+
+    #[test]
+    fn it_decrypts_basic_string() {
+        let result = vigenere("LXFOPVEFRNHR", "LEMON", Mode::Decrypt).unwrap();
+        assert_eq!("ATTACKATDAWN", result);
+    }
+
+    #[test]
+    fn it_cycles_a_keyword_shorter_than_the_input() {
+        let result = vigenere("AAAAAA", "AB", Mode::Encrypt).unwrap();
+        assert_eq!("ABABAB", result);
+    }
+
+    #[test]
+    fn it_ignores_but_keeps_non_alphabet_characters() {
+        let result = vigenere("(ATT)ACK", "LEMON", Mode::Encrypt).unwrap();
+        assert_eq!("(LXF)OPV", result);
+    }
+
+    #[test]
+    fn it_respects_capitalization() {
+        let result = vigenere("attack", "LEMON", Mode::Encrypt).unwrap();
+        assert_eq!("lxfopv", result);
+    }
+
+    #[test]
+    fn it_returns_error_on_empty_keyword() {
+        let result = vigenere("ATTACK", "", Mode::Encrypt).unwrap_err();
+        assert_eq!(KeywordError, result);
+    }
+
+    #[test]
+    fn it_returns_error_on_non_alphabetic_keyword() {
+        let result = vigenere("ATTACK", "LE1ON", Mode::Encrypt).unwrap_err();
+        assert_eq!(KeywordError, result);
+    }
+
+    #[test]
+    fn it_returns_error_on_non_ascii_alphabetic_keyword() {
+        let result = vigenere("ATTACK", "LE\u{f1}ON", Mode::Encrypt).unwrap_err();
+        assert_eq!(KeywordError, result);
+    }
+}